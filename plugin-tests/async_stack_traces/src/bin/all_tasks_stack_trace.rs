@@ -0,0 +1,51 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ all_tasks_stack_trace.rs:
+
+  print-stack-trace all-tasks executor.tasks
+  #check task 0:
+  #check   all_tasks_stack_trace::foo() [x=42]  at all_tasks_stack_trace.rs:32
+  #check     futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check task 1:
+  #check   all_tasks_stack_trace::foo() [x=7]  at all_tasks_stack_trace.rs:32
+  #check     futures_util::future::pending::Pending<u32>  (waker: <unset>)
+
+***/
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = u32>>>,
+}
+
+struct Executor {
+    tasks: Vec<Task>,
+}
+
+async fn foo(x: u32) -> u32 {
+    futures::future::pending::<u32>().await + x
+}
+
+fn main() {
+    let mut executor = Executor {
+        tasks: vec![
+            Task { future: Box::pin(foo(42)) },
+            Task { future: Box::pin(foo(7)) },
+        ],
+    };
+
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    for task in &mut executor.tasks {
+        assert_eq!(task.future.as_mut().poll(cx), Poll::Pending);
+    }
+
+    zzz(&executor); // #break
+}
@@ -0,0 +1,77 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ all_tasks_with_undecodable_task_stack_trace.rs:
+
+  print-stack-trace all-tasks executor.tasks
+  #check task 0:
+  #check   all_tasks_with_undecodable_task_stack_trace::foo() [x=42]  at all_tasks_with_undecodable_task_stack_trace.rs:57
+  #check     futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check task 1: <cannot decode all_tasks_with_undecodable_task_stack_trace::opaque_plugin::OpaqueFuture, skipped>
+  #check task 2:
+  #check   all_tasks_with_undecodable_task_stack_trace::foo() [x=7]  at all_tasks_with_undecodable_task_stack_trace.rs:57
+  #check     futures_util::future::pending::Pending<u32>  (waker: <unset>)
+
+***/
+
+// Task 1's future is `opaque_plugin::OpaqueFuture`, a hand-written `Future` impl
+// rather than a compiler-generated async-fn/coroutine state machine. Its DWARF type
+// exists (it's compiled in-tree), but its layout doesn't match any shape the walker
+// knows how to recurse into, so the decoder must fall back to a one-line "can't
+// decode this task" marker and keep walking the rest of the registry instead of
+// aborting the whole dump.
+
+mod opaque_plugin {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    pub struct OpaqueFuture;
+
+    impl Future for OpaqueFuture {
+        type Output = u32;
+
+        fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<u32> {
+            Poll::Pending
+        }
+    }
+}
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+use opaque_plugin::OpaqueFuture;
+
+struct Task {
+    future: Pin<Box<dyn Future<Output = u32>>>,
+}
+
+struct Executor {
+    tasks: Vec<Task>,
+}
+
+async fn foo(x: u32) -> u32 {
+    futures::future::pending::<u32>().await + x
+}
+
+fn main() {
+    let mut executor = Executor {
+        tasks: vec![
+            Task { future: Box::pin(foo(42)) },
+            Task { future: Box::pin(OpaqueFuture) },
+            Task { future: Box::pin(foo(7)) },
+        ],
+    };
+
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    for task in &mut executor.tasks {
+        assert_eq!(task.future.as_mut().poll(cx), Poll::Pending);
+    }
+
+    zzz(&executor); // #break
+}
@@ -5,15 +5,15 @@
   #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ basic_async_stack_trace.rs:
 
   print-stack-trace future
-  #check basic_async_stack_trace::foo() [x=42]
-  #check   basic_async_stack_trace::bar() [a=86, x=43]
+  #check basic_async_stack_trace::foo() [x=42]  at basic_async_stack_trace.rs:27
+  #check   basic_async_stack_trace::bar() [a=86, x=43]  at basic_async_stack_trace.rs:32
   #check   (SELECT)
-  #check   => basic_async_stack_trace::baz() [x=44, y=88]
-  #check        futures_util::future::pending::Pending<u32>
-  #check   => basic_async_stack_trace::baz() [x=88, y=176]
-  #check        futures_util::future::pending::Pending<u32>
-  #check   => basic_async_stack_trace::baz() [x=46, y=92]
-  #check        futures_util::future::pending::Pending<u32>
+  #check   => basic_async_stack_trace::baz() [x=44, y=88]  at basic_async_stack_trace.rs:37
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check   => basic_async_stack_trace::baz() [x=88, y=176]  at basic_async_stack_trace.rs:37
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check   => basic_async_stack_trace::baz() [x=46, y=92]  at basic_async_stack_trace.rs:37
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
 
 ***/
 
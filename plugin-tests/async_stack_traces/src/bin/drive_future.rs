@@ -0,0 +1,44 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ drive_future.rs:
+
+  drive-future future max=8
+  #check Ready(123) after 3 polls (wakes=2)
+
+***/
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+
+struct CountToReady {
+    remaining: u32,
+}
+
+impl Future for CountToReady {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        if self.remaining == 0 {
+            return Poll::Ready(123);
+        }
+
+        self.remaining -= 1;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+fn main() {
+    let mut future = Box::pin(CountToReady { remaining: 3 });
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.as_mut().poll(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}
@@ -0,0 +1,37 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ drive_future_max_polls.rs:
+
+  drive-future future max=8
+  #check Pending after 8 polls (wakes=8)
+
+***/
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+
+struct NeverReady;
+
+impl Future for NeverReady {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<u32> {
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+fn main() {
+    let mut future = Box::pin(NeverReady);
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.as_mut().poll(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}
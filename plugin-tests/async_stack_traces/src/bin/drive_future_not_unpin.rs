@@ -0,0 +1,41 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ drive_future_not_unpin.rs:
+
+  drive-future future max=8
+  #check error: future is not Unpin-safe to poll via drive-future
+
+***/
+
+use std::future::Future;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+
+struct SelfReferential {
+    _pinned: PhantomPinned,
+}
+
+impl Future for SelfReferential {
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<u32> {
+        Poll::Pending
+    }
+}
+
+fn main() {
+    let mut future = Box::pin(SelfReferential {
+        _pinned: PhantomPinned,
+    });
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.as_mut().poll(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}
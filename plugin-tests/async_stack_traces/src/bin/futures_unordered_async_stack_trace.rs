@@ -0,0 +1,54 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ futures_unordered_async_stack_trace.rs:
+
+  print-stack-trace future
+  #check futures_unordered_async_stack_trace::foo() [x=42]  at futures_unordered_async_stack_trace.rs:27
+  #check   futures_unordered_async_stack_trace::bar() [a=86, x=43]  at futures_unordered_async_stack_trace.rs:35
+  #check   (JOIN)
+  #check   => futures_unordered_async_stack_trace::baz() [x=44, y=88]  at futures_unordered_async_stack_trace.rs:43
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check   => futures_unordered_async_stack_trace::baz() [x=88, y=176]  at futures_unordered_async_stack_trace.rs:43
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check   => futures_unordered_async_stack_trace::baz() [x=46, y=92]  at futures_unordered_async_stack_trace.rs:43
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+
+***/
+
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::FutureExt;
+
+async fn foo(x: u32) -> u32 {
+    bar(x + 1).await + 7
+}
+
+async fn bar(x: u32) -> u32 {
+    let a = x * 2;
+    let mut pending: FuturesUnordered<_> =
+        [baz(x + 1), baz(a + 2), baz(x + 3)].into_iter().collect();
+    let mut total = 0;
+    while let Some(v) = pending.next().await {
+        total += v;
+    }
+    total + a
+}
+
+async fn baz(x: u32) -> u32 {
+    let y = x << 1;
+    futures::future::pending::<u32>().await + x * y
+}
+
+fn main() {
+    let mut future = Box::pin(foo(42));
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.poll_unpin(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}
@@ -0,0 +1,42 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ generator_panicked_stack_trace.rs:
+
+  print-stack-trace generator coro
+  #check Panicked
+
+***/
+
+#![feature(coroutines, coroutine_trait)]
+
+use std::ops::{Coroutine, CoroutineState};
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+
+use async_stack_traces::zzz;
+
+fn make_coro(x: u32) -> impl Coroutine<(), Yield = u32, Return = u32> {
+    #[coroutine]
+    move |_: ()| {
+        let a = x + 1;
+        yield a;
+        panic!("coroutine body blew up with a={a} x={x}");
+    }
+}
+
+fn main() {
+    let mut coro = Box::pin(make_coro(42));
+
+    match Pin::new(&mut coro).resume(()) {
+        CoroutineState::Yielded(_) => {}
+        CoroutineState::Complete(_) => unreachable!(),
+    }
+
+    let _ = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        Pin::new(&mut coro).resume(())
+    }));
+
+    zzz(&coro); // #break
+}
@@ -0,0 +1,37 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ generator_stack_trace.rs:
+
+  print-stack-trace generator coro
+  #check Suspend0 [a=43, x=42]
+
+***/
+
+#![feature(coroutines, coroutine_trait)]
+
+use std::ops::{Coroutine, CoroutineState};
+use std::pin::Pin;
+
+use async_stack_traces::zzz;
+
+fn make_coro(x: u32) -> impl Coroutine<(), Yield = u32, Return = u32> {
+    #[coroutine]
+    move |_: ()| {
+        let a = x + 1;
+        yield a;
+        a + x
+    }
+}
+
+fn main() {
+    let mut coro = Box::pin(make_coro(42));
+
+    match Pin::new(&mut coro).resume(()) {
+        CoroutineState::Yielded(_) => {}
+        CoroutineState::Complete(_) => unreachable!(),
+    }
+
+    zzz(&coro); // #break
+}
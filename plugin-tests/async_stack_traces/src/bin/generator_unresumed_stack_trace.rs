@@ -0,0 +1,32 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ generator_unresumed_stack_trace.rs:
+
+  print-stack-trace generator coro
+  #check Unresumed
+
+***/
+
+#![feature(coroutines, coroutine_trait)]
+
+use std::ops::Coroutine;
+use std::pin::Pin;
+
+use async_stack_traces::zzz;
+
+fn make_coro(x: u32) -> impl Coroutine<(), Yield = u32, Return = u32> {
+    #[coroutine]
+    move |_: ()| {
+        let a = x + 1;
+        yield a;
+        a + x
+    }
+}
+
+fn main() {
+    let coro: Pin<Box<dyn Coroutine<(), Yield = u32, Return = u32>>> = Box::pin(make_coro(42));
+
+    zzz(&coro); // #break
+}
@@ -0,0 +1,50 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ join_with_done_branch_async_stack_trace.rs:
+
+  print-stack-trace future
+  #check join_with_done_branch_async_stack_trace::foo() [x=42]  at join_with_done_branch_async_stack_trace.rs:28
+  #check   join_with_done_branch_async_stack_trace::bar() [a=86, x=43]  at join_with_done_branch_async_stack_trace.rs:33
+  #check   (JOIN)
+  #check   => join_with_done_branch_async_stack_trace::baz() [x=44, y=88]  at join_with_done_branch_async_stack_trace.rs:39
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+  #check   => join_with_done_branch_async_stack_trace::baz() [x=46, y=92]  at join_with_done_branch_async_stack_trace.rs:39
+  #check        futures_util::future::pending::Pending<u32>  (waker: <unset>)
+
+***/
+
+// One of the three joined branches below (`futures::future::ready`) completes on its
+// first poll, so the `MaybeDone::Done` slot it leaves behind must be skipped rather
+// than printed, leaving only the two still-pending branches in the (JOIN) tree.
+
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+use futures::FutureExt;
+
+async fn foo(x: u32) -> u32 {
+    bar(x + 1).await + 7
+}
+
+async fn bar(x: u32) -> u32 {
+    let a = x * 2;
+    let (p, q, r) = futures::future::join3(baz(x + 1), futures::future::ready(a), baz(x + 3)).await;
+    p + q + r + a
+}
+
+async fn baz(x: u32) -> u32 {
+    let y = x << 1;
+    futures::future::pending::<u32>().await + x * y
+}
+
+fn main() {
+    let mut future = Box::pin(foo(42));
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.poll_unpin(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}
@@ -0,0 +1,32 @@
+/***
+
+#if @gdb
+  run
+  #check Breakpoint @{ .* }@ main @{ .* }@ at @{ .* }@ leaf_waker_async_stack_trace.rs:
+
+  print-stack-trace future
+  #check leaf_waker_async_stack_trace::foo() [x=42]  at leaf_waker_async_stack_trace.rs:21
+  #check   futures_channel::oneshot::Receiver<u32>  (waker vtable: async_stack_traces::dummy_waker::DummyWaker)
+
+***/
+
+use std::task::{Context, Poll};
+
+use async_stack_traces::{dummy_waker, zzz};
+use futures::FutureExt;
+use futures_channel::oneshot;
+
+async fn foo(x: u32) -> u32 {
+    let (_tx, rx) = oneshot::channel::<u32>();
+    rx.await.unwrap_or(0) + x
+}
+
+fn main() {
+    let mut future = Box::pin(foo(42));
+    let waker = dummy_waker();
+    let cx = &mut Context::from_waker(&waker);
+
+    assert_eq!(future.poll_unpin(cx), Poll::Pending);
+
+    zzz(&future); // #break
+}